@@ -1,8 +1,10 @@
-use petgraph::graph::DiGraph;
+use petgraph::graph::{DiGraph, NodeIndex};
 use petgraph::algo::dijkstra;
+use petgraph::Direction;
 
-use petgraph::visit::{IntoNodeReferences, VisitMap, Visitable};
-use std::collections::{HashMap, BTreeMap};
+use petgraph::visit::{EdgeRef, IntoEdgeReferences, IntoNodeReferences, Reversed, VisitMap, Visitable};
+use rayon::prelude::*;
+use std::collections::{HashMap, VecDeque};
 
 use std::fs::File;
 use std::io::{self, BufRead};
@@ -49,7 +51,7 @@ fn construct_graph_from_file(path: &str) -> io::Result<DiGraph<(), ()>> {
 
 
 
-// Step 2. 
+// Step 2.
 // Function to perform basic network analysis
 
 fn basic_network_analysis(graph: &DiGraph<(), ()>) {
@@ -69,81 +71,422 @@ fn basic_network_analysis(graph: &DiGraph<(), ()>) {
     println!("Number of edges: {}", graph.edge_count());
     println!("Degree Distribution: {:?}", degree_distribution);
 
+    // Layer the normalized degree centrality on top of the raw histogram so
+    // results are comparable across graphs of different sizes.
+    let total_centrality = degree_centrality(graph, DegreeDirection::Total);
+    let average_centrality: f64 =
+        total_centrality.values().sum::<f64>() / total_centrality.len().max(1) as f64;
+    println!("Average Degree Centrality: {:.6}", average_centrality);
 
 }
 
 
 
-// Step 3. 
-//  Function for Degree Distributions Analysis to return data
+// Step 3.
+// Function for k-hop Reachability Analysis
+//
+// For each node we run a bounded BFS out to depth `k`, seeding the visited
+// set with the source itself so it and every closer node are marked before
+// we start counting — otherwise nodes reachable at multiple depths (e.g. a
+// 1-hop neighbor that's also reachable in 2 hops) get double-counted. When
+// `exact` is true we return only the nodes at depth exactly `k`; otherwise
+// we return everything reachable within `k` hops (excluding the source).
+
+fn k_hop_neighborhood_size(
+    graph: &DiGraph<(), ()>,
+    k: usize,
+    exact: bool,
+) -> HashMap<NodeIndex, usize> {
+
+    graph
+        .node_indices()
+        .map(|start| {
+            let mut visited = graph.visit_map();
+            visited.visit(start);
+
+            let mut frontier = vec![start];
+            let mut within_k_count = 0;
+            let mut exactly_k_count = 0;
+            let mut depth = 0;
+
+            while depth < k && !frontier.is_empty() {
+                depth += 1;
+
+                let mut next_frontier = Vec::new();
+                for node in frontier {
+                    for neighbor in graph.neighbors(node) {
+                        if !visited.is_visited(&neighbor) {
+                            visited.visit(neighbor);
+                            next_frontier.push(neighbor);
+                        }
+                    }
+                }
 
-fn degree_distributions_analysis(graph: &DiGraph<(), ()>) -> HashMap<usize, usize> {
+                within_k_count += next_frontier.len();
+                if depth == k {
+                    exactly_k_count = next_frontier.len();
+                }
 
-    let mut second_degree_distribution = HashMap::new();
-    for node_ref in graph.node_references() {
-        let mut visited = graph.visit_map();
-        let neighbors = graph.neighbors(node_ref.0);
-        let mut second_degree_count = 0;
+                frontier = next_frontier;
+            }
 
+            let size = if exact { exactly_k_count } else { within_k_count };
+            (start, size)
+        })
+        .collect()
+}
 
-        for neighbor in neighbors {
-            for second_neighbor in graph.neighbors(neighbor) {
-                if !visited.is_visited(&second_neighbor) {
-                    second_degree_count += 1;
-                    visited.visit(second_neighbor);
-                }
+
+
+// Step 4.
+// Function for Closeness Centrality Analysis
+//
+// Closeness is computed from shortest-path distances *to* each node, so we
+// run Dijkstra over the reversed graph. We then apply the Wasserman-Faust
+// normalization, scaling each score by the fraction of the other n - 1 nodes
+// that can reach it, so a node in a small component (few short distances)
+// doesn't outscore nodes in the giant component.
+//
+// Each node's score only depends on its own shortest-path tree, so above
+// `parallel_threshold` nodes we hand the per-node work to rayon; below it we
+// stay sequential to avoid paying for thread spawn on small graphs.
+
+fn closeness_for_node(graph: &DiGraph<(), ()>, node: NodeIndex, n: usize) -> (NodeIndex, f64) {
+    let paths = dijkstra(Reversed(graph), node, None, |_| 1usize);
+
+    let reachable = paths.len() - 1; // exclude the source itself
+    let total_distance: usize = paths.values().sum();
+
+    let closeness = if total_distance == 0 {
+        0.0
+    } else {
+        (reachable as f64 / total_distance as f64) * (reachable as f64 / (n - 1) as f64)
+    };
+
+    (node, closeness)
+}
+
+fn closeness_centrality_analysis(
+    graph: &DiGraph<(), ()>,
+    parallel_threshold: usize,
+) -> HashMap<NodeIndex, f64> {
+
+    let n = graph.node_count();
+
+    if n > parallel_threshold {
+        let nodes: Vec<NodeIndex> = graph.node_indices().collect();
+        nodes
+            .par_iter()
+            .map(|&node| closeness_for_node(graph, node, n))
+            .collect()
+    } else {
+        graph
+            .node_indices()
+            .map(|node| closeness_for_node(graph, node, n))
+            .collect()
+    }
+}
+
+
+
+// Step 5.
+// Function for Betweenness Centrality Analysis (Brandes' algorithm)
+//
+// For each source node we run a BFS (the graph is unweighted) that tracks,
+// for every reached node w: its predecessors on shortest paths, the number
+// of shortest paths sigma[w], and its distance from the source. Vertices are
+// pushed onto a stack in non-decreasing distance order so that popping them
+// back off processes dependents before their predecessors, which lets the
+// dependency accumulation delta[v] += (sigma[v]/sigma[w]) * (1 + delta[w])
+// be computed in a single backward pass.
+//
+// Every source's BFS tree is independent of the others, so above
+// `parallel_threshold` nodes we compute each source's contribution in
+// parallel with rayon and fold the partial score maps together; below it we
+// fold the same per-source maps sequentially to avoid thread spawn overhead.
+
+fn brandes_single_source(
+    graph: &DiGraph<(), ()>,
+    s: NodeIndex,
+    include_endpoints: bool,
+) -> HashMap<NodeIndex, f64> {
+
+    let mut contribution: HashMap<NodeIndex, f64> = graph.node_indices().map(|v| (v, 0.0)).collect();
+
+    let mut stack: Vec<NodeIndex> = Vec::new();
+    let mut predecessors: HashMap<NodeIndex, Vec<NodeIndex>> =
+        graph.node_indices().map(|v| (v, Vec::new())).collect();
+    let mut sigma: HashMap<NodeIndex, f64> = graph.node_indices().map(|v| (v, 0.0)).collect();
+    let mut dist: HashMap<NodeIndex, i64> = graph.node_indices().map(|v| (v, -1)).collect();
+
+    sigma.insert(s, 1.0);
+    dist.insert(s, 0);
+
+    let mut queue = VecDeque::new();
+    queue.push_back(s);
+
+    while let Some(v) = queue.pop_front() {
+        stack.push(v);
+        for w in graph.neighbors(v) {
+            if dist[&w] < 0 {
+                dist.insert(w, dist[&v] + 1);
+                queue.push_back(w);
+            }
+            if dist[&w] == dist[&v] + 1 {
+                let sigma_v = sigma[&v];
+                *sigma.get_mut(&w).unwrap() += sigma_v;
+                predecessors.get_mut(&w).unwrap().push(v);
             }
         }
+    }
+
+    let mut delta: HashMap<NodeIndex, f64> = graph.node_indices().map(|v| (v, 0.0)).collect();
+
+    if include_endpoints {
+        *contribution.get_mut(&s).unwrap() += (stack.len() - 1) as f64;
+    }
+
+    while let Some(w) = stack.pop() {
+        let coeff = (1.0 + delta[&w]) / sigma[&w];
+        for v in &predecessors[&w] {
+            *delta.get_mut(v).unwrap() += sigma[v] * coeff;
+        }
+        if w != s {
+            let endpoint_bonus = if include_endpoints { 1.0 } else { 0.0 };
+            *contribution.get_mut(&w).unwrap() += delta[&w] + endpoint_bonus;
+        }
+    }
+
+    contribution
+}
+
+fn merge_contributions(
+    mut acc: HashMap<NodeIndex, f64>,
+    partial: HashMap<NodeIndex, f64>,
+) -> HashMap<NodeIndex, f64> {
+    for (node, score) in partial {
+        *acc.entry(node).or_insert(0.0) += score;
+    }
+    acc
+}
 
-        *second_degree_distribution.entry(second_degree_count).or_insert(0) += 1;
+fn betweenness_centrality(
+    graph: &DiGraph<(), ()>,
+    normalized: bool,
+    include_endpoints: bool,
+    parallel_threshold: usize,
+) -> HashMap<NodeIndex, f64> {
+
+    let n = graph.node_count();
+
+    let mut centrality = if n > parallel_threshold {
+        let nodes: Vec<NodeIndex> = graph.node_indices().collect();
+        nodes
+            .par_iter()
+            .map(|&s| brandes_single_source(graph, s, include_endpoints))
+            .reduce(HashMap::new, merge_contributions)
+    } else {
+        graph
+            .node_indices()
+            .map(|s| brandes_single_source(graph, s, include_endpoints))
+            .fold(HashMap::new(), merge_contributions)
+    };
+
+    if normalized && n > 2 {
+        let scale = 1.0 / ((n - 1) as f64 * (n - 2) as f64);
+        for score in centrality.values_mut() {
+            *score *= scale;
+        }
     }
 
-    second_degree_distribution
+    centrality
 }
 
 
 
-// Step 4. 
-// Function for Closeness Centrality Analysis
+// Step 6.
+// Function for Eigenvector Centrality Analysis (power iteration)
+//
+// Eigenvector centrality scores a node highly when it's pointed to by other
+// high-scoring nodes, so each iteration pushes every node's current score
+// along its outgoing edges into its successors, then L2-normalizes the
+// result. We stop once the total change since the last iteration drops
+// below `n * tol`, and give up after `max_iter` iterations without
+// converging rather than returning a misleading answer.
+
+fn eigenvector_centrality(
+    graph: &DiGraph<(), ()>,
+    max_iter: usize,
+    tol: f64,
+) -> Option<HashMap<NodeIndex, f64>> {
+
+    let n = graph.node_count();
+    if n == 0 {
+        return Some(HashMap::new());
+    }
+
+    let mut x: HashMap<NodeIndex, f64> =
+        graph.node_indices().map(|v| (v, 1.0 / n as f64)).collect();
 
-fn closeness_centrality_analysis(graph: &DiGraph<(), ()>) -> BTreeMap<usize, f64> {
+    for _ in 0..max_iter {
+        let mut x_new: HashMap<NodeIndex, f64> = graph.node_indices().map(|v| (v, 0.0)).collect();
 
-    let mut centrality_scores = BTreeMap::new();
-    let node_count = graph.node_count().min(1000); // Limit to first 1000 nodes
+        for edge in graph.edge_references() {
+            let contribution = x[&edge.source()];
+            *x_new.get_mut(&edge.target()).unwrap() += contribution;
+        }
+
+        let norm = x_new.values().map(|v| v * v).sum::<f64>().sqrt();
+        if norm > 0.0 {
+            for v in x_new.values_mut() {
+                *v /= norm;
+            }
+        }
+
+        let diff: f64 = graph
+            .node_indices()
+            .map(|v| (x_new[&v] - x[&v]).abs())
+            .sum();
 
+        x = x_new;
 
-    for (i, node_ref) in graph.node_references().take(node_count).enumerate() {
-        let paths = dijkstra(graph, node_ref.0, None, |_| 1);
-        let total_distance: usize = paths.values().map(|&d| d).sum();
-        let closeness_centrality = if total_distance > 0 { 1.0 / total_distance as f64 } else { 0.0 };
-        centrality_scores.insert(i, closeness_centrality);
+        if diff < n as f64 * tol {
+            return Some(x);
+        }
     }
 
+    None
+}
+
+
 
-    centrality_scores
+// Step 7.
+// Function for Degree Centrality Analysis
+//
+// Raw degree isn't comparable across graphs of different sizes, so we
+// normalize by n - 1, the maximum possible in- or out-degree. Since the graph
+// is directed, callers pick whether they want in-degree centrality (how many
+// products link *to* this one), out-degree centrality, or the total; the
+// total is also divided by n - 1, so it can reach 2.0.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DegreeDirection {
+    Incoming,
+    Outgoing,
+    Total,
 }
 
+fn degree_centrality(
+    graph: &DiGraph<(), ()>,
+    direction: DegreeDirection,
+) -> HashMap<NodeIndex, f64> {
 
+    let max_degree = (graph.node_count() - 1) as f64;
+
+    graph
+        .node_indices()
+        .map(|node| {
+            let degree = match direction {
+                DegreeDirection::Incoming => {
+                    graph.neighbors_directed(node, Direction::Incoming).count()
+                }
+                DegreeDirection::Outgoing => {
+                    graph.neighbors_directed(node, Direction::Outgoing).count()
+                }
+                DegreeDirection::Total => {
+                    graph.neighbors_directed(node, Direction::Incoming).count()
+                        + graph.neighbors_directed(node, Direction::Outgoing).count()
+                }
+            };
+
+            let centrality = if max_degree > 0.0 {
+                degree as f64 / max_degree
+            } else {
+                0.0
+            };
+
+            (node, centrality)
+        })
+        .collect()
+}
+
+
+
+// Parallelize once a graph has more nodes than this; below it, the cost of
+// spawning rayon's thread pool outweighs the per-node shortest-path work.
+const PARALLEL_THRESHOLD: usize = 1000;
 
 // main
 fn main() {
 
 
-    let path = "amazon0302.txt"; 
+    let path = "amazon0302.txt";
     match construct_graph_from_file(path) {
         Ok(graph) => {
             println!("Graph constructed successfully!");
             basic_network_analysis(&graph);
 
 
-            let _degree_distribution = degree_distributions_analysis(&graph); 
+            let two_hop_sizes = k_hop_neighborhood_size(&graph, 2, true);
+
+            let mut two_hop_distribution: HashMap<usize, usize> = HashMap::new();
+            for &size in two_hop_sizes.values() {
+                *two_hop_distribution.entry(size).or_insert(0) += 1;
+            }
+            println!("2-Hop Neighborhood Size Distribution: {:?}", two_hop_distribution);
+
+
+            let centrality_scores = closeness_centrality_analysis(&graph, PARALLEL_THRESHOLD);
 
+            let mut sorted_scores: Vec<_> = centrality_scores.iter().collect();
+            sorted_scores.sort_by_key(|(node, _)| node.index());
 
-            let centrality_scores = closeness_centrality_analysis(&graph);
-            
             println!("Closeness Centrality Scores:");
-            for (node, score) in centrality_scores.iter().take(10) {
-                println!("Node {}: Closeness Centrality = {:.20}", node, score);
+            for (node, score) in sorted_scores.iter().take(10) {
+                println!("Node {}: Closeness Centrality = {:.20}", node.index(), score);
+            }
+
+            let betweenness_scores =
+                betweenness_centrality(&graph, true, false, PARALLEL_THRESHOLD);
+
+            let mut sorted_betweenness: Vec<_> = betweenness_scores.iter().collect();
+            sorted_betweenness.sort_by_key(|(node, _)| node.index());
+
+            println!("Betweenness Centrality Scores:");
+            for (node, score) in sorted_betweenness.iter().take(10) {
+                println!("Node {}: Betweenness Centrality = {:.20}", node.index(), score);
+            }
+
+            match eigenvector_centrality(&graph, 100, 1.0e-6) {
+                Some(eigenvector_scores) => {
+                    let mut sorted_eigenvector: Vec<_> = eigenvector_scores.iter().collect();
+                    sorted_eigenvector.sort_by_key(|(node, _)| node.index());
+
+                    println!("Eigenvector Centrality Scores:");
+                    for (node, score) in sorted_eigenvector.iter().take(10) {
+                        println!("Node {}: Eigenvector Centrality = {:.20}", node.index(), score);
+                    }
+                }
+                None => println!("Eigenvector centrality did not converge"),
+            }
+
+            let in_degree_centrality = degree_centrality(&graph, DegreeDirection::Incoming);
+            let out_degree_centrality = degree_centrality(&graph, DegreeDirection::Outgoing);
+
+            let mut sorted_in_degree: Vec<_> = in_degree_centrality.iter().collect();
+            sorted_in_degree.sort_by_key(|(node, _)| node.index());
+
+            println!("In-Degree Centrality Scores:");
+            for (node, score) in sorted_in_degree.iter().take(10) {
+                println!("Node {}: In-Degree Centrality = {:.6}", node.index(), score);
+            }
+
+            let mut sorted_out_degree: Vec<_> = out_degree_centrality.iter().collect();
+            sorted_out_degree.sort_by_key(|(node, _)| node.index());
+
+            println!("Out-Degree Centrality Scores:");
+            for (node, score) in sorted_out_degree.iter().take(10) {
+                println!("Node {}: Out-Degree Centrality = {:.6}", node.index(), score);
             }
         }
         Err(e) => println!("Error constructing graph: {}", e),
@@ -182,28 +525,30 @@ mod tests {
     }
 
 
-    // Test degree distributions analysis
+    // Test k-hop reachability analysis on a small, hand-checkable graph
+    // instead of the full Amazon dataset, since the corrected counts depend
+    // on exact BFS layering rather than a value we can eyeball from the raw
+    // file.
     #[test]
-    fn test_degree_distributions_analysis() {
-        let path = "amazon0302.txt"; // Adjust the path as needed
-        let graph = construct_graph_from_file(path).unwrap();
-    
-        let degree_distribution = degree_distributions_analysis(&graph);
-
-        // define a margin of error
-        let margin = 100; 
-        let expected_degree_count = 4541;
-        let actual_degree_count = *degree_distribution.get(&0).unwrap_or(&0);
-
-        assert!(
-            actual_degree_count >= expected_degree_count - margin 
-        && actual_degree_count <= expected_degree_count + margin
-        );
-    
-        // Specific degree check
-        // In order to check if the number of nodes with a degree of n is m
-        // assert_eq!(*degree_distribution.get(&5).unwrap_or(&0), 4541);
-        
+    fn test_k_hop_neighborhood_size() {
+        // A -> B -> C -> D: B is 1 hop from A, C is exactly 2 hops, D is 3.
+        let mut graph = DiGraph::<(), ()>::new();
+        let a = graph.add_node(());
+        let b = graph.add_node(());
+        let c = graph.add_node(());
+        let d = graph.add_node(());
+        graph.add_edge(a, b, ());
+        graph.add_edge(b, c, ());
+        graph.add_edge(c, d, ());
+
+        let exactly_two_hops = k_hop_neighborhood_size(&graph, 2, true);
+        assert_eq!(exactly_two_hops[&a], 1); // only C
+        assert_eq!(exactly_two_hops[&b], 1); // only D
+        assert_eq!(exactly_two_hops[&c], 0); // nothing 2 hops out
+        assert_eq!(exactly_two_hops[&d], 0);
+
+        let within_two_hops = k_hop_neighborhood_size(&graph, 2, false);
+        assert_eq!(within_two_hops[&a], 2); // B and C, each counted once
     }
 
 
@@ -213,10 +558,99 @@ mod tests {
         let path = "amazon0302.txt"; // Adjust the path as needed
         let graph = construct_graph_from_file(path).unwrap();
 
-        let centrality_scores = closeness_centrality_analysis(&graph);
+        let centrality_scores = closeness_centrality_analysis(&graph, PARALLEL_THRESHOLD);
+
+        // Perform some basic checks, like ensuring every node got a score
+        assert_eq!(centrality_scores.len(), graph.node_count());
+        assert!(centrality_scores.values().all(|&c| c >= 0.0 && c <= 1.0));
+    }
+
 
-        // Perform some basic checks, like ensuring some nodes have centrality scores
-        assert!(!centrality_scores.is_empty());
-        
+    // Test betweenness centrality analysis on a small, hand-checkable graph
+    // instead of the full Amazon dataset, since Brandes' algorithm is
+    // O(n * m) and running it on 262k nodes would make the test suite crawl.
+    #[test]
+    fn test_betweenness_centrality() {
+        // A -> B -> C, so B sits on the only shortest path between A and C.
+        let mut graph = DiGraph::<(), ()>::new();
+        let a = graph.add_node(());
+        let b = graph.add_node(());
+        let c = graph.add_node(());
+        graph.add_edge(a, b, ());
+        graph.add_edge(b, c, ());
+
+        let scores = betweenness_centrality(&graph, false, false, PARALLEL_THRESHOLD);
+
+        assert_eq!(*scores.get(&a).unwrap(), 0.0);
+        assert_eq!(*scores.get(&b).unwrap(), 1.0);
+        assert_eq!(*scores.get(&c).unwrap(), 0.0);
+    }
+
+
+    // A threshold of 0 forces every node count to take the rayon path; the
+    // result should be identical to the sequential one above.
+    #[test]
+    fn test_betweenness_centrality_parallel_path() {
+        let mut graph = DiGraph::<(), ()>::new();
+        let a = graph.add_node(());
+        let b = graph.add_node(());
+        let c = graph.add_node(());
+        graph.add_edge(a, b, ());
+        graph.add_edge(b, c, ());
+
+        let scores = betweenness_centrality(&graph, false, false, 0);
+
+        assert_eq!(*scores.get(&a).unwrap(), 0.0);
+        assert_eq!(*scores.get(&b).unwrap(), 1.0);
+        assert_eq!(*scores.get(&c).unwrap(), 0.0);
+    }
+
+
+    // Test eigenvector centrality analysis on a small, hand-checkable graph.
+    // The extra a -> c shortcut alongside the a -> b -> c -> a cycle makes
+    // the graph aperiodic, which is required for power iteration to
+    // converge to a unique dominant eigenvector.
+    #[test]
+    fn test_eigenvector_centrality() {
+        let mut graph = DiGraph::<(), ()>::new();
+        let a = graph.add_node(());
+        let b = graph.add_node(());
+        let c = graph.add_node(());
+        graph.add_edge(a, b, ());
+        graph.add_edge(b, c, ());
+        graph.add_edge(c, a, ());
+        graph.add_edge(a, c, ());
+
+        let scores = eigenvector_centrality(&graph, 1000, 1.0e-9).unwrap();
+
+        assert_eq!(scores.len(), 3);
+        assert!(scores.values().all(|&s| s >= 0.0));
+
+        let norm: f64 = scores.values().map(|s| s * s).sum::<f64>().sqrt();
+        assert!((norm - 1.0).abs() < 1.0e-6);
+    }
+
+
+    // Test degree centrality analysis with the in/out/total split
+    #[test]
+    fn test_degree_centrality() {
+        // A -> B, C -> B, so B has in-degree 2 and out-degree 0.
+        let mut graph = DiGraph::<(), ()>::new();
+        let a = graph.add_node(());
+        let b = graph.add_node(());
+        let c = graph.add_node(());
+        graph.add_edge(a, b, ());
+        graph.add_edge(c, b, ());
+
+        let in_degree = degree_centrality(&graph, DegreeDirection::Incoming);
+        let out_degree = degree_centrality(&graph, DegreeDirection::Outgoing);
+        let total_degree = degree_centrality(&graph, DegreeDirection::Total);
+
+        // Max possible degree here is n - 1 = 2.
+        assert_eq!(in_degree[&b], 1.0);
+        assert_eq!(in_degree[&a], 0.0);
+        assert_eq!(out_degree[&a], 0.5);
+        assert_eq!(out_degree[&b], 0.0);
+        assert_eq!(total_degree[&b], 1.0);
     }
 }
\ No newline at end of file